@@ -0,0 +1,137 @@
+use std::f32::consts::FRAC_PI_2;
+
+use winit::event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent};
+use winit::keyboard::{KeyCode, PhysicalKey};
+
+const PITCH_LIMIT: f32 = FRAC_PI_2 - 0.01;
+const ROTATE_SPEED: f32 = 0.005;
+const ZOOM_SPEED: f32 = 0.5;
+const PAN_SPEED: f32 = 2.0;
+const MIN_DISTANCE: f32 = 0.5;
+
+/// An orbit camera: it always looks at `target` from `distance` units away
+/// at the given `yaw`/`pitch`. Left-drag rotates, the wheel zooms, and WASD
+/// pans `target` within the camera's local ground plane.
+pub struct Camera {
+    pub target: glam::Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    dragging: bool,
+    last_cursor: Option<(f64, f64)>,
+    move_forward: bool,
+    move_back: bool,
+    move_left: bool,
+    move_right: bool,
+}
+
+impl Camera {
+    pub fn new(distance: f32) -> Self {
+        Self {
+            target: glam::Vec3::ZERO,
+            yaw: -std::f32::consts::FRAC_PI_4,
+            pitch: 0.5,
+            distance,
+            dragging: false,
+            last_cursor: None,
+            move_forward: false,
+            move_back: false,
+            move_left: false,
+            move_right: false,
+        }
+    }
+
+    pub fn eye(&self) -> glam::Vec3 {
+        let direction = glam::Vec3::new(
+            self.yaw.cos() * self.pitch.cos(),
+            self.yaw.sin() * self.pitch.cos(),
+            self.pitch.sin(),
+        );
+        self.target - direction * self.distance
+    }
+
+    pub fn view_matrix(&self) -> glam::Mat4 {
+        glam::Mat4::look_at_rh(self.eye(), self.target, glam::Vec3::Z)
+    }
+
+    pub fn update(&mut self, event: &WindowEvent) {
+        match event {
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.dragging = *state == ElementState::Pressed;
+                if !self.dragging {
+                    self.last_cursor = None;
+                }
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                if self.dragging {
+                    if let Some((last_x, last_y)) = self.last_cursor {
+                        let dx = (position.x - last_x) as f32;
+                        let dy = (position.y - last_y) as f32;
+                        self.yaw += dx * ROTATE_SPEED;
+                        self.pitch =
+                            (self.pitch + dy * ROTATE_SPEED).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+                    }
+                }
+                self.last_cursor = Some((position.x, position.y));
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                let scroll = match delta {
+                    MouseScrollDelta::LineDelta(_, y) => *y,
+                    MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 20.0,
+                };
+                self.distance = (self.distance - scroll * ZOOM_SPEED).max(MIN_DISTANCE);
+            }
+            WindowEvent::KeyboardInput {
+                event:
+                    winit::event::KeyEvent {
+                        physical_key: PhysicalKey::Code(code),
+                        state,
+                        ..
+                    },
+                ..
+            } => {
+                let pressed = *state == ElementState::Pressed;
+                match code {
+                    KeyCode::KeyW => self.move_forward = pressed,
+                    KeyCode::KeyS => self.move_back = pressed,
+                    KeyCode::KeyA => self.move_left = pressed,
+                    KeyCode::KeyD => self.move_right = pressed,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Pans `target` according to the currently held WASD keys. Called once
+    /// per rendered frame since the framework drives a continuous redraw loop.
+    pub fn tick(&mut self, dt: f32) {
+        if !(self.move_forward || self.move_back || self.move_left || self.move_right) {
+            return;
+        }
+
+        // The view direction flattened onto the ground plane.
+        let forward = glam::Vec3::new(self.yaw.cos(), self.yaw.sin(), 0.0);
+        let right = forward.cross(glam::Vec3::Z).normalize_or_zero();
+
+        let mut delta = glam::Vec3::ZERO;
+        if self.move_forward {
+            delta += forward;
+        }
+        if self.move_back {
+            delta -= forward;
+        }
+        if self.move_right {
+            delta += right;
+        }
+        if self.move_left {
+            delta -= right;
+        }
+
+        self.target += delta.normalize_or_zero() * PAN_SPEED * dt;
+    }
+}
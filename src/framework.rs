@@ -1,3 +1,4 @@
+use crate::postprocess::{self, PostProcessChain};
 use simple_wgpu::{Context, RenderTexture};
 use std::sync::Arc;
 use wgpu::Surface;
@@ -30,24 +31,139 @@ pub enum ShaderStage {
     Compute,
 }
 
+/// Default multisample count used for the framework's offscreen color
+/// target when the caller doesn't pick one. `1` disables MSAA entirely;
+/// `4` and `8` are the usual hardware-supported steps.
+pub const DEFAULT_SAMPLE_COUNT: u32 = 4;
+
 pub trait Main: 'static + Sized {
     fn init(
         config: &wgpu::SurfaceConfiguration,
         adapter: &wgpu::Adapter,
         context: &Context,
+        sample_count: u32,
     ) -> Self;
     fn resize(&mut self, config: &wgpu::SurfaceConfiguration, context: &Context);
     fn update(&mut self, event: WindowEvent);
-    fn render(&mut self, target: &RenderTexture, context: &Context);
+    fn render(
+        &mut self,
+        target: &RenderTexture,
+        resolve_target: &RenderTexture,
+        depth: &DepthTexture,
+        context: &Context,
+    );
+}
+
+/// A managed depth texture sized to match the surface, recreated on resize.
+pub struct DepthTexture {
+    #[allow(dead_code)]
+    texture: wgpu::Texture,
+    pub view: wgpu::TextureView,
+}
+
+impl DepthTexture {
+    pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+    fn new(device: &wgpu::Device, size: PhysicalSize<u32>, sample_count: u32) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Depth Texture"),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: Self::FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self { texture, view }
+    }
+}
+
+/// The multisampled color texture the scene is drawn into when the sample
+/// count is above 1; the scene's render pass resolves it into `SceneTexture`.
+struct MsaaTexture {
+    #[allow(dead_code)]
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl MsaaTexture {
+    fn new(
+        device: &wgpu::Device,
+        surface_format: TextureFormat,
+        size: PhysicalSize<u32>,
+        sample_count: u32,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("MSAA Framebuffer"),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: surface_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[surface_format.add_srgb_suffix()],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            format: Some(surface_format.add_srgb_suffix()),
+            ..Default::default()
+        });
+
+        Self { texture, view }
+    }
 }
 
-pub fn run<E: Main>(_title: &str) {
+/// The offscreen target the scene is resolved into (or, at a sample count
+/// of 1, drawn into directly) before the post-processing chain runs. Sampled rather than presented directly, so
+/// later passes can read it as a texture.
+struct SceneTexture {
+    #[allow(dead_code)]
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl SceneTexture {
+    fn new(device: &wgpu::Device, surface_format: TextureFormat, size: PhysicalSize<u32>) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Scene Target"),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: surface_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[surface_format.add_srgb_suffix()],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            format: Some(surface_format.add_srgb_suffix()),
+            ..Default::default()
+        });
+
+        Self { texture, view }
+    }
+}
+
+pub fn run<E: Main>(_title: &str, sample_count: u32) {
     env_logger::init();
 
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
 
-    let mut app = App::<E> { state: None };
+    let mut app = App::<E> { sample_count, state: None };
     event_loop.run_app(&mut app).unwrap();
 }
 
@@ -57,6 +173,11 @@ struct State<E: Main> {
     surface_format: wgpu::TextureFormat,
     surface: Surface<'static>,
     context: Context,
+    sample_count: u32,
+    depth_texture: DepthTexture,
+    msaa_texture: MsaaTexture,
+    scene_texture: SceneTexture,
+    post_process: PostProcessChain,
     example: E,
 }
 
@@ -78,7 +199,7 @@ fn build_surface_config(
 }
 
 impl<E: Main> State<E> {
-    async fn new(window: Arc<Window>) -> State<E> {
+    async fn new(window: Arc<Window>, sample_count: u32) -> State<E> {
         let size = window.inner_size();
 
         let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
@@ -102,9 +223,18 @@ impl<E: Main> State<E> {
         let surface_format = cap.formats[0];
 
         let config = build_surface_config(&surface_format, size);
+        let depth_texture = DepthTexture::new(context.device(), size, sample_count);
+        let msaa_texture = MsaaTexture::new(context.device(), surface_format, size, sample_count);
+        let scene_texture = SceneTexture::new(context.device(), surface_format, size);
+        let post_process = PostProcessChain::new(
+            &context,
+            surface_format.add_srgb_suffix(),
+            postprocess::default_preset(),
+            size,
+        );
 
         log::info!("Initializing the example...");
-        let example = E::init(&config, &adapter, &context);
+        let example = E::init(&config, &adapter, &context, sample_count);
 
         let state = State {
             window,
@@ -112,6 +242,11 @@ impl<E: Main> State<E> {
             surface_format,
             surface,
             context,
+            sample_count,
+            depth_texture,
+            msaa_texture,
+            scene_texture,
+            post_process,
             example,
         };
 
@@ -131,6 +266,15 @@ impl<E: Main> State<E> {
 
         // reconfigure the surface
         self.configure_surface();
+        self.depth_texture = DepthTexture::new(self.context.device(), self.size, self.sample_count);
+        self.msaa_texture = MsaaTexture::new(
+            self.context.device(),
+            self.surface_format,
+            self.size,
+            self.sample_count,
+        );
+        self.scene_texture = SceneTexture::new(self.context.device(), self.surface_format, self.size);
+        self.post_process.resize(self.context.device(), self.size);
 
         let config = build_surface_config(&self.surface_format, self.size);
 
@@ -152,10 +296,29 @@ impl<E: Main> State<E> {
                 ..Default::default()
             });
 
-        let target =
-            RenderTexture::from_texture_view(&texture_view, &self.surface_format.add_srgb_suffix());
+        let scene_resolve_target =
+            RenderTexture::from_texture_view(&self.scene_texture.view, &self.surface_format.add_srgb_suffix());
+        // Without MSAA there is nothing to resolve, so the scene is drawn
+        // straight into the scene texture the post-process chain samples.
+        let target_view = if self.sample_count > 1 {
+            &self.msaa_texture.view
+        } else {
+            &self.scene_texture.view
+        };
+        let target = RenderTexture::from_texture_view(
+            target_view,
+            &self.surface_format.add_srgb_suffix(),
+        );
+
+        self.example.render(
+            &target,
+            &scene_resolve_target,
+            &self.depth_texture,
+            &self.context,
+        );
 
-        self.example.render(&target, &self.context);
+        self.post_process
+            .run(&self.context, &self.scene_texture.view, &texture_view);
 
         surface_texture.present();
     }
@@ -166,6 +329,7 @@ impl<E: Main> State<E> {
 }
 
 struct App<E: Main> {
+    sample_count: u32,
     state: Option<State<E>>,
 }
 
@@ -178,7 +342,7 @@ impl<E: Main> ApplicationHandler for App<E> {
             .unwrap(),
         );
 
-        let state = pollster::block_on(State::new(window.clone()));
+        let state = pollster::block_on(State::new(window.clone(), self.sample_count));
         self.state = Some(state);
 
         window.request_redraw();
@@ -1,6 +1,10 @@
+mod camera;
 mod framework;
+mod obj;
+mod postprocess;
 
 use bytemuck::{Pod, Zeroable};
+use camera::Camera;
 use simple_wgpu::{
     BindGroup, BindGroupBuilder, Buffer, ColorAttachment, ColorTargetState, CommandEncoder,
     Context, DrawCall, RasteriserState, RenderPipeline, RenderPipelineBuilder, RenderTexture,
@@ -14,59 +18,165 @@ use wgpu::include_wgsl;
 
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
-struct Vertex {
+pub(crate) struct Vertex {
+    pub(crate) position: [f32; 4],
+    pub(crate) color: [f32; 4],  // Using color instead of texture coordinates
+    pub(crate) normal: [f32; 4],
+}
+
+// ----- Lighting -----
+
+// Mirrors the WGSL `LightUniform` layout; `_padding` keeps the struct at the
+// 16-byte-aligned size the uniform address space expects.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct LightUniform {
     position: [f32; 4],
-    color: [f32; 4],  // Using color instead of texture coordinates
+    color: [f32; 4],
+    ambient: f32,
+    _padding: [f32; 3],
+}
+
+const LIGHT_ORBIT_RADIUS: f32 = 8.0;
+const LIGHT_ORBIT_SPEED: f32 = 0.5;
+
+// ----- Camera Uniform -----
+
+// Carries the eye position alongside the view-projection matrix so the
+// fragment shader can reconstruct a view direction for specular lighting.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct CameraUniform {
+    view_proj: [[f32; 4]; 4],
+    eye_position: [f32; 4],
+}
+
+impl CameraUniform {
+    fn new(camera: &Camera, aspect_ratio: f32) -> Self {
+        Self {
+            view_proj: Cube::create_view_projection_matrix(camera, aspect_ratio).to_cols_array_2d(),
+            eye_position: camera.eye().extend(1.0).into(),
+        }
+    }
+}
+
+// ----- Instance Data Structure -----
+
+// Per-instance model matrix and color tint, so a whole voxel grid can be
+// drawn from a single mesh in one instanced draw call.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct Instance {
+    model: [[f32; 4]; 4],
+    color: [f32; 4],
+}
+
+impl Instance {
+    fn new(translation: glam::Vec3, color: [f32; 4]) -> Self {
+        Self {
+            model: glam::Mat4::from_translation(translation).to_cols_array_2d(),
+            color,
+        }
+    }
 }
 
+const VOXEL_GRID_SIZE: i32 = 4;
+const VOXEL_SPACING: f32 = 2.5;
+
 // ----- Cube Implementation -----
 
 struct Cube {
     vertex_buffer: Buffer,
     index_buffer: Buffer,
     index_count: usize,
+    instance_buffer: Buffer,
+    instance_count: usize,
     uniform_buffer: Buffer,
+    light: LightUniform,
+    light_angle: f32,
+    light_buffer: Buffer,
     bind_group: BindGroup,
     render_pipeline: RenderPipeline,
     wireframe_pipeline: Option<RenderPipeline>,
+    camera: Camera,
+    aspect_ratio: f32,
+    sample_count: u32,
 }
 
+/// Assumed frame interval used to advance the camera's WASD pan; the
+/// framework drives a continuous redraw loop rather than a timed one.
+const FRAME_TIME: f32 = 1.0 / 60.0;
+
 impl Cube {
-    fn new(config: &wgpu::SurfaceConfiguration, context: &Context) -> Self {
-        // Create vertex and index data
-        let (vertices, indices) = Self::create_cube_geometry();
-        
-        // Create buffers
-        let vertex_buffer = Buffer::with_data(
-            Some("Cube Vertices"),
-            wgpu::BufferUsages::VERTEX,
-            bytemuck::cast_slice(&vertices),
-            context,
-        );
+    fn new(config: &wgpu::SurfaceConfiguration, context: &Context, sample_count: u32) -> Self {
+        // Create vertex and index data. `--obj <path>` swaps the procedural
+        // voxel grid for a single instance of an arbitrary loaded mesh.
+        let (vertex_buffer, index_buffer, index_count, instances) =
+            if let Some(path) = Self::obj_path_from_args() {
+                let loaded = obj::load_obj(&path, context)
+                    .unwrap_or_else(|err| panic!("failed to load OBJ mesh '{path}': {err}"));
+                let index_count = loaded
+                    .draw_ranges
+                    .iter()
+                    .map(|range| range.element_range.len())
+                    .sum();
+                let instances = vec![Instance::new(glam::Vec3::ZERO, [1.0, 1.0, 1.0, 1.0])];
+                (loaded.vertex_buffer, loaded.index_buffer, index_count, instances)
+            } else {
+                let (vertices, indices) = Self::create_cube_geometry();
+
+                let vertex_buffer = Buffer::with_data(
+                    Some("Cube Vertices"),
+                    wgpu::BufferUsages::VERTEX,
+                    bytemuck::cast_slice(&vertices),
+                    context,
+                );
+                let index_buffer = Buffer::with_data(
+                    Some("Cube Indices"),
+                    wgpu::BufferUsages::INDEX,
+                    bytemuck::cast_slice(&indices),
+                    context,
+                );
 
-        let index_buffer = Buffer::with_data(
-            Some("Cube Indices"),
-            wgpu::BufferUsages::INDEX,
-            bytemuck::cast_slice(&indices),
+                (vertex_buffer, index_buffer, indices.len(), Self::create_instances())
+            };
+
+        let instance_buffer = Buffer::with_data(
+            Some("Cube Instances"),
+            wgpu::BufferUsages::VERTEX,
+            bytemuck::cast_slice(&instances),
             context,
         );
 
         // Create transformation matrix
         let aspect_ratio = config.width as f32 / config.height as f32;
-        let transform_matrix = Self::create_view_projection_matrix(aspect_ratio);
-        let transform_ref: &[f32; 16] = transform_matrix.as_ref();
-        
+        let camera = Camera::new(6.0);
+        let camera_uniform = CameraUniform::new(&camera, aspect_ratio);
+
         // Create uniform buffer
         let uniform_buffer = Buffer::with_data(
-            Some("Transform Matrix"),
+            Some("Camera Uniform"),
             wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-            bytemuck::cast_slice(transform_ref),
+            bytemuck::bytes_of(&camera_uniform),
             context,
         );
 
-        // Create bind group - much simpler now without texture
+        let light_angle = 0.0f32;
+        let light = Self::light_at_angle(light_angle);
+        let light_buffer = Buffer::with_data(
+            Some("Light Uniform"),
+            wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            bytemuck::bytes_of(&light),
+            context,
+        );
+
+        // Create bind group
+        // Binding 0 carries `eye_position` alongside `view_proj`, and
+        // `fs_main` reads it to build the specular view direction, so it
+        // must be visible to both stages, not just the vertex shader.
         let bind_group = BindGroupBuilder::new()
-            .buffer(0, wgpu::ShaderStages::VERTEX, &uniform_buffer.uniform_binding(), None)
+            .buffer(0, wgpu::ShaderStages::VERTEX_FRAGMENT, &uniform_buffer.uniform_binding(), None)
+            .buffer(1, wgpu::ShaderStages::FRAGMENT, &light_buffer.uniform_binding(), None)
             .build();
 
         // Create shader
@@ -79,6 +189,8 @@ impl Cube {
             vertex_layout.clone(),
         )
         .fragment(&shader.entry_point("fs_main"), [Some(Default::default())])
+        .depth_stencil(Self::depth_stencil_state())
+        .multisample(Self::multisample_state(sample_count))
         .build();
 
         // Create wireframe pipeline if supported
@@ -101,8 +213,10 @@ impl Cube {
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
             )
+            .depth_stencil(Self::depth_stencil_state())
+            .multisample(Self::multisample_state(sample_count))
             .build();
-            
+
             Some(pipeline)
         } else {
             None
@@ -111,33 +225,136 @@ impl Cube {
         Self {
             vertex_buffer,
             index_buffer,
-            index_count: indices.len(),
+            index_count,
+            instance_count: instances.len(),
+            instance_buffer,
             uniform_buffer,
+            light,
+            light_angle,
+            light_buffer,
             bind_group,
             render_pipeline,
             wireframe_pipeline,
+            camera,
+            aspect_ratio,
+            sample_count,
         }
     }
 
-    fn create_vertex_layout() -> [VertexBufferLayout; 1] {
+    /// Looks for a `--obj <path>` command-line argument so the renderer can
+    /// load an arbitrary mesh instead of the procedural voxel grid.
+    fn obj_path_from_args() -> Option<String> {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--obj" {
+                return args.next();
+            }
+        }
+        None
+    }
+
+    fn depth_stencil_state() -> wgpu::DepthStencilState {
+        wgpu::DepthStencilState {
+            format: framework::DepthTexture::FORMAT,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::LessEqual,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }
+    }
+
+    fn multisample_state(sample_count: u32) -> wgpu::MultisampleState {
+        wgpu::MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        }
+    }
+
+    fn create_vertex_layout() -> Vec<VertexBufferLayout> {
         let vertex_size = mem::size_of::<Vertex>();
-        
-        [VertexBufferLayout {
-            array_stride: vertex_size as wgpu::BufferAddress,
-            step_mode: wgpu::VertexStepMode::Vertex,
-            attributes: vec![
-                wgpu::VertexAttribute {
-                    format: wgpu::VertexFormat::Float32x4,
-                    offset: 0,
-                    shader_location: 0,
-                },
-                wgpu::VertexAttribute {
-                    format: wgpu::VertexFormat::Float32x4,
-                    offset: 4 * 4,
-                    shader_location: 1,
-                },
-            ],
-        }]
+        let instance_size = mem::size_of::<Instance>();
+
+        vec![
+            VertexBufferLayout {
+                array_stride: vertex_size as wgpu::BufferAddress,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: vec![
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 0,
+                        shader_location: 0,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 4 * 4,
+                        shader_location: 1,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 8 * 4,
+                        shader_location: 7,
+                    },
+                ],
+            },
+            VertexBufferLayout {
+                array_stride: instance_size as wgpu::BufferAddress,
+                step_mode: wgpu::VertexStepMode::Instance,
+                attributes: vec![
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 0,
+                        shader_location: 2,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 4 * 4,
+                        shader_location: 3,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 8 * 4,
+                        shader_location: 4,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 12 * 4,
+                        shader_location: 5,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x4,
+                        offset: 16 * 4,
+                        shader_location: 6,
+                    },
+                ],
+            },
+        ]
+    }
+
+    fn create_instances() -> Vec<Instance> {
+        let half = VOXEL_GRID_SIZE as f32 / 2.0 - 0.5;
+        let mut instances = Vec::with_capacity((VOXEL_GRID_SIZE.pow(3)) as usize);
+
+        for x in 0..VOXEL_GRID_SIZE {
+            for y in 0..VOXEL_GRID_SIZE {
+                for z in 0..VOXEL_GRID_SIZE {
+                    let translation = glam::Vec3::new(
+                        (x as f32 - half) * VOXEL_SPACING,
+                        (y as f32 - half) * VOXEL_SPACING,
+                        (z as f32 - half) * VOXEL_SPACING,
+                    );
+                    let tint = [
+                        x as f32 / VOXEL_GRID_SIZE as f32,
+                        y as f32 / VOXEL_GRID_SIZE as f32,
+                        z as f32 / VOXEL_GRID_SIZE as f32,
+                        1.0,
+                    ];
+                    instances.push(Instance::new(translation, tint));
+                }
+            }
+        }
+
+        instances
     }
 
     fn create_cube_geometry() -> (Vec<Vertex>, Vec<u16>) {
@@ -149,42 +366,49 @@ impl Cube {
         let front_color = [0.9, 0.9, 0.3, 1.0];    // Yellowish
         let back_color = [0.3, 0.9, 0.9, 1.0];     // Cyanish
         
+        let top_normal = [0.0, 0.0, 1.0, 0.0];
+        let bottom_normal = [0.0, 0.0, -1.0, 0.0];
+        let right_normal = [1.0, 0.0, 0.0, 0.0];
+        let left_normal = [-1.0, 0.0, 0.0, 0.0];
+        let front_normal = [0.0, 1.0, 0.0, 0.0];
+        let back_normal = [0.0, -1.0, 0.0, 0.0];
+
         let vertices = [
             // Top face (0, 0, 1)
-            Vertex { position: [-1.0, -1.0, 1.0, 1.0], color: top_color },
-            Vertex { position: [1.0, -1.0, 1.0, 1.0], color: top_color },
-            Vertex { position: [1.0, 1.0, 1.0, 1.0], color: top_color },
-            Vertex { position: [-1.0, 1.0, 1.0, 1.0], color: top_color },
-            
+            Vertex { position: [-1.0, -1.0, 1.0, 1.0], color: top_color, normal: top_normal },
+            Vertex { position: [1.0, -1.0, 1.0, 1.0], color: top_color, normal: top_normal },
+            Vertex { position: [1.0, 1.0, 1.0, 1.0], color: top_color, normal: top_normal },
+            Vertex { position: [-1.0, 1.0, 1.0, 1.0], color: top_color, normal: top_normal },
+
             // Bottom face (0, 0, -1)
-            Vertex { position: [-1.0, 1.0, -1.0, 1.0], color: bottom_color },
-            Vertex { position: [1.0, 1.0, -1.0, 1.0], color: bottom_color },
-            Vertex { position: [1.0, -1.0, -1.0, 1.0], color: bottom_color },
-            Vertex { position: [-1.0, -1.0, -1.0, 1.0], color: bottom_color },
-            
+            Vertex { position: [-1.0, 1.0, -1.0, 1.0], color: bottom_color, normal: bottom_normal },
+            Vertex { position: [1.0, 1.0, -1.0, 1.0], color: bottom_color, normal: bottom_normal },
+            Vertex { position: [1.0, -1.0, -1.0, 1.0], color: bottom_color, normal: bottom_normal },
+            Vertex { position: [-1.0, -1.0, -1.0, 1.0], color: bottom_color, normal: bottom_normal },
+
             // Right face (1, 0, 0)
-            Vertex { position: [1.0, -1.0, -1.0, 1.0], color: right_color },
-            Vertex { position: [1.0, 1.0, -1.0, 1.0], color: right_color },
-            Vertex { position: [1.0, 1.0, 1.0, 1.0], color: right_color },
-            Vertex { position: [1.0, -1.0, 1.0, 1.0], color: right_color },
-            
+            Vertex { position: [1.0, -1.0, -1.0, 1.0], color: right_color, normal: right_normal },
+            Vertex { position: [1.0, 1.0, -1.0, 1.0], color: right_color, normal: right_normal },
+            Vertex { position: [1.0, 1.0, 1.0, 1.0], color: right_color, normal: right_normal },
+            Vertex { position: [1.0, -1.0, 1.0, 1.0], color: right_color, normal: right_normal },
+
             // Left face (-1, 0, 0)
-            Vertex { position: [-1.0, -1.0, 1.0, 1.0], color: left_color },
-            Vertex { position: [-1.0, 1.0, 1.0, 1.0], color: left_color },
-            Vertex { position: [-1.0, 1.0, -1.0, 1.0], color: left_color },
-            Vertex { position: [-1.0, -1.0, -1.0, 1.0], color: left_color },
-            
+            Vertex { position: [-1.0, -1.0, 1.0, 1.0], color: left_color, normal: left_normal },
+            Vertex { position: [-1.0, 1.0, 1.0, 1.0], color: left_color, normal: left_normal },
+            Vertex { position: [-1.0, 1.0, -1.0, 1.0], color: left_color, normal: left_normal },
+            Vertex { position: [-1.0, -1.0, -1.0, 1.0], color: left_color, normal: left_normal },
+
             // Front face (0, 1, 0)
-            Vertex { position: [1.0, 1.0, -1.0, 1.0], color: front_color },
-            Vertex { position: [-1.0, 1.0, -1.0, 1.0], color: front_color },
-            Vertex { position: [-1.0, 1.0, 1.0, 1.0], color: front_color },
-            Vertex { position: [1.0, 1.0, 1.0, 1.0], color: front_color },
-            
+            Vertex { position: [1.0, 1.0, -1.0, 1.0], color: front_color, normal: front_normal },
+            Vertex { position: [-1.0, 1.0, -1.0, 1.0], color: front_color, normal: front_normal },
+            Vertex { position: [-1.0, 1.0, 1.0, 1.0], color: front_color, normal: front_normal },
+            Vertex { position: [1.0, 1.0, 1.0, 1.0], color: front_color, normal: front_normal },
+
             // Back face (0, -1, 0)
-            Vertex { position: [1.0, -1.0, 1.0, 1.0], color: back_color },
-            Vertex { position: [-1.0, -1.0, 1.0, 1.0], color: back_color },
-            Vertex { position: [-1.0, -1.0, -1.0, 1.0], color: back_color },
-            Vertex { position: [1.0, -1.0, -1.0, 1.0], color: back_color },
+            Vertex { position: [1.0, -1.0, 1.0, 1.0], color: back_color, normal: back_normal },
+            Vertex { position: [-1.0, -1.0, 1.0, 1.0], color: back_color, normal: back_normal },
+            Vertex { position: [-1.0, -1.0, -1.0, 1.0], color: back_color, normal: back_normal },
+            Vertex { position: [1.0, -1.0, -1.0, 1.0], color: back_color, normal: back_normal },
         ];
 
         let indices: Vec<u16> = vec![
@@ -199,20 +423,34 @@ impl Cube {
         (vertices.to_vec(), indices)
     }
 
-    fn create_view_projection_matrix(aspect_ratio: f32) -> glam::Mat4 {
+    fn create_view_projection_matrix(camera: &Camera, aspect_ratio: f32) -> glam::Mat4 {
         let projection = glam::Mat4::perspective_rh(consts::FRAC_PI_4, aspect_ratio, 1.0, 10.0);
-        let view = glam::Mat4::look_at_rh(
-            glam::Vec3::new(1.5f32, -5.0, 3.0),
-            glam::Vec3::ZERO,
-            glam::Vec3::Z,
-        );
-        projection * view
+        projection * camera.view_matrix()
     }
 
-    fn update_transform_matrix(&mut self, aspect_ratio: f32, context: &Context) {
-        let transform = Self::create_view_projection_matrix(aspect_ratio);
-        let transform_ref: &[f32; 16] = transform.as_ref();
-        self.uniform_buffer.write(bytemuck::cast_slice(transform_ref), context);
+    fn update_transform_matrix(&mut self, context: &Context) {
+        let camera_uniform = CameraUniform::new(&self.camera, self.aspect_ratio);
+        self.uniform_buffer.write(bytemuck::bytes_of(&camera_uniform), context);
+    }
+
+    fn light_at_angle(angle: f32) -> LightUniform {
+        LightUniform {
+            position: [
+                angle.cos() * LIGHT_ORBIT_RADIUS,
+                angle.sin() * LIGHT_ORBIT_RADIUS,
+                LIGHT_ORBIT_RADIUS * 0.5,
+                1.0,
+            ],
+            color: [1.0, 1.0, 1.0, 1.0],
+            ambient: 0.1,
+            _padding: [0.0; 3],
+        }
+    }
+
+    fn update_light(&mut self, context: &Context) {
+        self.light_angle += FRAME_TIME * LIGHT_ORBIT_SPEED;
+        self.light = Self::light_at_angle(self.light_angle);
+        self.light_buffer.write(bytemuck::bytes_of(&self.light), context);
     }
 }
 
@@ -223,29 +461,49 @@ impl framework::Main for Cube {
         config: &wgpu::SurfaceConfiguration,
         _adapter: &wgpu::Adapter,
         context: &Context,
+        sample_count: u32,
     ) -> Self {
-        Cube::new(config, context)
+        Cube::new(config, context, sample_count)
     }
 
-    fn update(&mut self, _event: winit::event::WindowEvent) {
-        // Empty - No dynamic updates in this simple example
+    fn update(&mut self, event: winit::event::WindowEvent) {
+        self.camera.update(&event);
     }
 
     fn resize(&mut self, config: &wgpu::SurfaceConfiguration, context: &Context) {
-        let aspect_ratio = config.width as f32 / config.height as f32;
-        self.update_transform_matrix(aspect_ratio, context);
+        self.aspect_ratio = config.width as f32 / config.height as f32;
+        self.update_transform_matrix(context);
     }
 
-    fn render(&mut self, target: &RenderTexture, context: &Context) {
+    fn render(
+        &mut self,
+        target: &RenderTexture,
+        resolve_target: &RenderTexture,
+        depth: &framework::DepthTexture,
+        context: &Context,
+    ) {
+        self.camera.tick(FRAME_TIME);
+        self.update_transform_matrix(context);
+        self.update_light(context);
+
         context.device().push_error_scope(wgpu::ErrorFilter::Validation);
         let mut encoder = CommandEncoder::new(None, context);
-        
+
+        // A resolve target is only valid on a multisampled attachment; at
+        // sample_count 1, `target` is already single-sampled, so resolving
+        // it would fail render pass validation.
+        let resolve_target = if self.sample_count > 1 {
+            Some(resolve_target.clone())
+        } else {
+            None
+        };
+
         // Begin render pass
         let mut render_pass = encoder.render_pass(
             None,
             vec![ColorAttachment {
                 target: target.clone(),
-                resolve_target: None,
+                resolve_target,
                 ops: wgpu::Operations {
                     load: wgpu::LoadOp::Clear(wgpu::Color {
                         r: 0.1,
@@ -256,7 +514,14 @@ impl framework::Main for Cube {
                     store: wgpu_types::StoreOp::Store,
                 },
             }],
-            None,
+            Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &depth.view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu_types::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
             Some(Default::default()),
         );
 
@@ -265,10 +530,10 @@ impl framework::Main for Cube {
             bind_groups: vec![self.bind_group.clone()],
             bind_group_offsets: vec![vec![]],
             pipeline: self.render_pipeline.clone(),
-            vertices: vec![self.vertex_buffer.slice(..)],
+            vertices: vec![self.vertex_buffer.slice(..), self.instance_buffer.slice(..)],
             indices: Some(self.index_buffer.slice(..)),
             element_range: 0..self.index_count,
-            instance_range: 0..1,
+            instance_range: 0..self.instance_count,
             rasteriser_state: RasteriserState {
                 cull_mode: Some(wgpu::Face::Back),
                 ..Default::default()
@@ -281,10 +546,10 @@ impl framework::Main for Cube {
                 bind_groups: vec![self.bind_group.clone()],
                 bind_group_offsets: vec![vec![]],
                 pipeline: pipeline.clone(),
-                vertices: vec![self.vertex_buffer.slice(..)],
+                vertices: vec![self.vertex_buffer.slice(..), self.instance_buffer.slice(..)],
                 indices: Some(self.index_buffer.slice(..)),
                 element_range: 0..self.index_count,
-                instance_range: 0..1,
+                instance_range: 0..self.instance_count,
                 rasteriser_state: RasteriserState {
                     cull_mode: Some(wgpu::Face::Back),
                     polygon_mode: wgpu::PolygonMode::Line,
@@ -296,5 +561,5 @@ impl framework::Main for Cube {
 }
 
 fn main() {
-    framework::run::<Cube>("Simple Colored Cube");
+    framework::run::<Cube>("Simple Colored Cube", framework::DEFAULT_SAMPLE_COUNT);
 }
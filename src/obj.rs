@@ -0,0 +1,123 @@
+use crate::Vertex;
+use simple_wgpu::{Buffer, Context};
+use std::ops::Range;
+use std::path::Path;
+
+/// One material-colored slice of `LoadedObj::index_buffer`; feed
+/// `element_range` straight into a `DrawCall`.
+pub struct DrawRange {
+    pub element_range: Range<usize>,
+    pub material_color: [f32; 4],
+}
+
+/// A Wavefront OBJ file loaded into the crate's `Vertex` format. All
+/// sub-meshes share one vertex/index buffer pair; `draw_ranges` slices the
+/// index buffer back into its per-mesh, per-material pieces.
+///
+/// The index buffer is always `u16`: the draw path (`Cube::new`,
+/// `DrawCall`) has no way to select an index format per draw, so a
+/// combined mesh that would need `u32` indices is rejected up front
+/// rather than silently emitted as bytes the renderer can't interpret.
+pub struct LoadedObj {
+    pub vertex_buffer: Buffer,
+    pub index_buffer: Buffer,
+    pub draw_ranges: Vec<DrawRange>,
+}
+
+pub fn load_obj(path: impl AsRef<Path>, context: &Context) -> tobj::LoadResult<LoadedObj> {
+    let (models, materials) = tobj::load_obj(
+        path.as_ref(),
+        &tobj::LoadOptions {
+            triangulate: true,
+            single_index: true,
+            ..Default::default()
+        },
+    )?;
+    let materials = materials?;
+
+    let mut vertices: Vec<Vertex> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut draw_ranges = Vec::with_capacity(models.len());
+
+    for model in models {
+        let mesh = model.mesh;
+        let material_color = mesh
+            .material_id
+            .and_then(|id| materials.get(id))
+            .map(|material| [material.diffuse[0], material.diffuse[1], material.diffuse[2], 1.0])
+            .unwrap_or([1.0, 1.0, 1.0, 1.0]);
+
+        let base_vertex = vertices.len() as u32;
+        let vertex_count = mesh.positions.len() / 3;
+
+        for i in 0..vertex_count {
+            let position = [
+                mesh.positions[i * 3],
+                mesh.positions[i * 3 + 1],
+                mesh.positions[i * 3 + 2],
+                1.0,
+            ];
+            let normal = if mesh.normals.len() >= (i + 1) * 3 {
+                [
+                    mesh.normals[i * 3],
+                    mesh.normals[i * 3 + 1],
+                    mesh.normals[i * 3 + 2],
+                    0.0,
+                ]
+            } else {
+                [0.0, 0.0, 1.0, 0.0]
+            };
+            let color = if mesh.vertex_color.len() >= (i + 1) * 3 {
+                [
+                    mesh.vertex_color[i * 3],
+                    mesh.vertex_color[i * 3 + 1],
+                    mesh.vertex_color[i * 3 + 2],
+                    1.0,
+                ]
+            } else {
+                material_color
+            };
+
+            vertices.push(Vertex { position, color, normal });
+        }
+
+        let start = indices.len();
+        indices.extend(mesh.indices.iter().map(|index| base_vertex + index));
+        draw_ranges.push(DrawRange {
+            element_range: start..indices.len(),
+            material_color,
+        });
+    }
+
+    // The draw path always issues u16 index buffers, so a combined mesh
+    // that has outgrown u16 can't be rendered correctly - fail loudly here
+    // instead of emitting u32 bytes the renderer would misinterpret as u16.
+    assert!(
+        vertices.len() <= u16::MAX as usize,
+        "OBJ '{}' has {} combined vertices, which overflows the u16 index \
+         buffer the render path supports; split the mesh or add u32 index \
+         support to the draw path first",
+        path.as_ref().display(),
+        vertices.len(),
+    );
+    let indices16: Vec<u16> = indices.iter().map(|&index| index as u16).collect();
+
+    let vertex_buffer = Buffer::with_data(
+        Some("OBJ Vertices"),
+        wgpu::BufferUsages::VERTEX,
+        bytemuck::cast_slice(&vertices),
+        context,
+    );
+    let index_buffer = Buffer::with_data(
+        Some("OBJ Indices"),
+        wgpu::BufferUsages::INDEX,
+        bytemuck::cast_slice(&indices16),
+        context,
+    );
+
+    Ok(LoadedObj {
+        vertex_buffer,
+        index_buffer,
+        draw_ranges,
+    })
+}
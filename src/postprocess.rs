@@ -0,0 +1,220 @@
+use simple_wgpu::{
+    BindGroupBuilder, Buffer, ColorAttachment, CommandEncoder, Context, DrawCall,
+    RasteriserState, RenderPipeline, RenderPipelineBuilder, RenderTexture, Shader,
+};
+use winit::dpi::PhysicalSize;
+
+/// Describes one stage of the post-processing chain: a fullscreen fragment
+/// shader that samples the previous stage's output, and the resolution it
+/// renders at relative to the swapchain (1.0 = native resolution). The last
+/// pass in a chain always renders into the swapchain view handed to
+/// `PostProcessChain::run`, so its `scale` and `format` are not used.
+pub struct PostProcessPassDesc {
+    pub label: &'static str,
+    pub shader: wgpu::ShaderModuleDescriptor<'static>,
+    pub scale: f32,
+    /// Output texture format for this pass; `None` reuses the chain's
+    /// default (the swapchain's sRGB format). Set this for a pass that
+    /// needs more range than that, e.g. `Rgba16Float` for an HDR bloom
+    /// accumulation buffer.
+    pub format: Option<wgpu::TextureFormat>,
+    /// Raw bytes for an optional per-pass parameter uniform, bound at
+    /// binding 2 (`None` skips the binding entirely). Build these with
+    /// `bytemuck::bytes_of` on a `Pod` struct, the same way `CameraUniform`
+    /// and `LightUniform` are uploaded in `main.rs`.
+    pub params: Option<Vec<u8>>,
+}
+
+/// The default preset: a single passthrough pass that copies the rendered
+/// scene straight to the swapchain. Add entries (FXAA, bloom, color
+/// grading, ...) to chain real effects without touching the framework.
+pub fn default_preset() -> Vec<PostProcessPassDesc> {
+    vec![PostProcessPassDesc {
+        label: "Passthrough",
+        shader: wgpu::include_wgsl!("passthrough.wgsl"),
+        scale: 1.0,
+        format: None,
+        params: None,
+    }]
+}
+
+struct OffscreenTarget {
+    #[allow(dead_code)]
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl OffscreenTarget {
+    fn new(device: &wgpu::Device, format: wgpu::TextureFormat, size: PhysicalSize<u32>) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Post-Process Target"),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self { texture, view }
+    }
+}
+
+struct PostProcessPass {
+    label: &'static str,
+    pipeline: RenderPipeline,
+    sampler: wgpu::Sampler,
+    format: wgpu::TextureFormat,
+    scale: f32,
+    // Bound at binding 2 alongside the input texture/sampler when the
+    // pass's `PostProcessPassDesc::params` was `Some`.
+    params_buffer: Option<Buffer>,
+    // `None` for the last pass in the chain, which renders into the
+    // swapchain view handed to `PostProcessChain::run` instead.
+    output: Option<OffscreenTarget>,
+}
+
+/// An ordered chain of fullscreen passes that resolves the scene rendered by
+/// the framework down to the swapchain, each pass sampling the previous
+/// pass's output texture.
+pub struct PostProcessChain {
+    format: wgpu::TextureFormat,
+    passes: Vec<PostProcessPass>,
+}
+
+impl PostProcessChain {
+    pub fn new(
+        context: &Context,
+        format: wgpu::TextureFormat,
+        presets: Vec<PostProcessPassDesc>,
+        size: PhysicalSize<u32>,
+    ) -> Self {
+        let device = context.device();
+        let passes = presets
+            .into_iter()
+            .map(|desc| {
+                let shader = Shader::new(desc.shader, context);
+                let pipeline = RenderPipelineBuilder::with_vertex(&shader.entry_point("vs_main"), vec![])
+                    .fragment(&shader.entry_point("fs_main"), [Some(Default::default())])
+                    .build();
+
+                let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+                    label: Some(desc.label),
+                    mag_filter: wgpu::FilterMode::Linear,
+                    min_filter: wgpu::FilterMode::Linear,
+                    ..Default::default()
+                });
+
+                let params_buffer = desc.params.map(|bytes| {
+                    Buffer::with_data(
+                        Some(desc.label),
+                        wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                        &bytes,
+                        context,
+                    )
+                });
+
+                PostProcessPass {
+                    label: desc.label,
+                    pipeline,
+                    sampler,
+                    format: desc.format.unwrap_or(format),
+                    scale: desc.scale,
+                    params_buffer,
+                    output: None,
+                }
+            })
+            .collect();
+
+        let mut chain = Self { format, passes };
+        chain.resize(device, size);
+        chain
+    }
+
+    /// Recreates every intermediate pass target at the new surface size. The
+    /// final pass has no target of its own - it always renders into whatever
+    /// swapchain view `run` is given.
+    pub fn resize(&mut self, device: &wgpu::Device, size: PhysicalSize<u32>) {
+        let last = self.passes.len().saturating_sub(1);
+
+        for (index, pass) in self.passes.iter_mut().enumerate() {
+            pass.output = if index == last {
+                None
+            } else {
+                let scaled = PhysicalSize::new(
+                    ((size.width as f32) * pass.scale).max(1.0) as u32,
+                    ((size.height as f32) * pass.scale).max(1.0) as u32,
+                );
+                Some(OffscreenTarget::new(device, pass.format, scaled))
+            };
+        }
+    }
+
+    /// Runs every pass in order, starting from the resolved scene texture
+    /// and ending at `swapchain_view`.
+    pub fn run(
+        &self,
+        context: &Context,
+        scene_view: &wgpu::TextureView,
+        swapchain_view: &wgpu::TextureView,
+    ) {
+        let mut input_view = scene_view;
+
+        for pass in &self.passes {
+            let output_view = pass.output.as_ref().map_or(swapchain_view, |t| &t.view);
+
+            let mut bind_group_builder = BindGroupBuilder::new()
+                .texture(0, wgpu::ShaderStages::FRAGMENT, input_view, None)
+                .sampler(1, wgpu::ShaderStages::FRAGMENT, &pass.sampler, None);
+            if let Some(params_buffer) = &pass.params_buffer {
+                bind_group_builder = bind_group_builder.buffer(
+                    2,
+                    wgpu::ShaderStages::FRAGMENT,
+                    &params_buffer.uniform_binding(),
+                    None,
+                );
+            }
+            let bind_group = bind_group_builder.build();
+
+            let mut encoder = CommandEncoder::new(Some(pass.label), context);
+            // The final pass renders into the real swapchain view, which
+            // always has the chain's format; only an intermediate pass's
+            // own offscreen target can use its per-pass format.
+            let target_format = pass.output.as_ref().map_or(self.format, |_| pass.format);
+            let target = RenderTexture::from_texture_view(output_view, &target_format);
+            let mut render_pass = encoder.render_pass(
+                Some(pass.label),
+                vec![ColorAttachment {
+                    target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu_types::StoreOp::Store,
+                    },
+                }],
+                None,
+                Some(Default::default()),
+            );
+
+            render_pass.draw(DrawCall {
+                bind_groups: vec![bind_group],
+                bind_group_offsets: vec![vec![]],
+                pipeline: pass.pipeline.clone(),
+                vertices: vec![],
+                indices: None,
+                element_range: 0..3,
+                instance_range: 0..1,
+                rasteriser_state: RasteriserState::default(),
+            });
+
+            drop(render_pass);
+            input_view = output_view;
+        }
+    }
+}